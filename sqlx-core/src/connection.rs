@@ -0,0 +1,67 @@
+use crate::logger::StatementFormat;
+use log::LevelFilter;
+use std::time::Duration;
+
+/// Settings controlling how queries are logged by [`crate::logger::QueryLogger`].
+#[derive(Debug, Clone)]
+pub struct LogSettings {
+    pub statements_level: LevelFilter,
+    pub slow_statements_level: LevelFilter,
+    pub slow_statements_duration: Duration,
+    /// Fraction of non-slow statements to log, in `0.0..=1.0`.
+    ///
+    /// A single draw is made per logger; statements that lose the draw emit no
+    /// event. Slow statements always bypass sampling. Defaults to `1.0`, which
+    /// preserves the historic always-log behavior.
+    pub sample_rate: f64,
+    /// How the statement's SQL is rendered before it is logged. Defaults to
+    /// `sqlformat` pretty-printing, matching the historic behavior.
+    pub statement_format: StatementFormat,
+    /// Value recorded as the span's `db.system` attribute, following the
+    /// OpenTelemetry semantic conventions (`"postgresql"`, `"mysql"`,
+    /// `"sqlite"`). Each backend sets this when building its logger; defaults
+    /// to `"postgresql"`.
+    pub db_system: &'static str,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        LogSettings {
+            statements_level: LevelFilter::Info,
+            slow_statements_level: LevelFilter::Warn,
+            slow_statements_duration: Duration::from_secs(1),
+            sample_rate: 1.0,
+            statement_format: StatementFormat::default(),
+            db_system: "postgresql",
+        }
+    }
+}
+
+impl LogSettings {
+    pub fn log_statements(&mut self, level: LevelFilter) {
+        self.statements_level = level;
+    }
+
+    pub fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) {
+        self.slow_statements_level = level;
+        self.slow_statements_duration = duration;
+    }
+
+    /// Log only a fraction of non-slow statements. `rate` is clamped to
+    /// `0.0..=1.0`; `1.0` logs everything, `0.0` suppresses non-slow events.
+    pub fn log_sample_rate(&mut self, rate: f64) {
+        self.sample_rate = rate.clamp(0.0, 1.0);
+    }
+
+    /// Control how the logged SQL is rendered.
+    pub fn log_statement_format(&mut self, format: StatementFormat) {
+        self.statement_format = format;
+    }
+
+    /// Set the `db.system` attribute recorded on the query span. Backends call
+    /// this with their OpenTelemetry system name (`"postgresql"`, `"mysql"`,
+    /// `"sqlite"`) so traces are categorized correctly by APM tooling.
+    pub fn set_db_system(&mut self, db_system: &'static str) {
+        self.db_system = db_system;
+    }
+}
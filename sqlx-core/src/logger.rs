@@ -1,5 +1,9 @@
 use crate::connection::LogSettings;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 use regex::RegexBuilder;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
 // Yes these look silly. `tracing` doesn't currently support dynamic levels
@@ -39,6 +43,24 @@ macro_rules! private_tracing_dynamic_event {
     }};
 }
 
+// Yes these look silly too. Same dynamic-level dance as the event macro above,
+// but opening a span instead of emitting an event.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! private_tracing_dynamic_span {
+    (target: $target:expr, $level:expr, $($args:tt)*) => {{
+        use ::tracing::Level;
+
+        match $level {
+            Level::ERROR => ::tracing::span!(target: $target, Level::ERROR, $($args)*),
+            Level::WARN => ::tracing::span!(target: $target, Level::WARN, $($args)*),
+            Level::INFO => ::tracing::span!(target: $target, Level::INFO, $($args)*),
+            Level::DEBUG => ::tracing::span!(target: $target, Level::DEBUG, $($args)*),
+            Level::TRACE => ::tracing::span!(target: $target, Level::TRACE, $($args)*),
+        }
+    }};
+}
+
 #[doc(hidden)]
 pub fn private_level_filter_to_levels(
     filter: log::LevelFilter,
@@ -56,7 +78,78 @@ pub fn private_level_filter_to_levels(
 }
 
 pub use sqlformat;
-use tracing::{info_span, Span};
+use tracing::span::EnteredSpan;
+
+/// Controls how [`QueryLogger`] renders a statement's SQL before logging it.
+///
+/// Carried on `LogSettings` so callers can trade pretty-printing for output
+/// that matches what they wrote, or for grep-friendly single-line pipelines.
+#[derive(Debug, Clone)]
+pub enum StatementFormat {
+    /// Reformat the SQL with `sqlformat` using the supplied options.
+    Pretty(SqlFormatOptions),
+    /// Log the `trim_query`-dedented SQL verbatim, skipping `sqlformat`.
+    Verbatim,
+    /// Collapse the statement onto a single line.
+    SingleLine,
+}
+
+impl Default for StatementFormat {
+    fn default() -> Self {
+        StatementFormat::Pretty(SqlFormatOptions::default())
+    }
+}
+
+/// Owned mirror of the `sqlformat::FormatOptions` knobs that make sense to
+/// expose through `LogSettings`.
+#[derive(Debug, Clone)]
+pub struct SqlFormatOptions {
+    /// Indentation per level: one tab if the string contains a tab, otherwise
+    /// as many spaces as the string is long (its characters are not copied).
+    pub indent: String,
+    /// Uppercase reserved keywords.
+    pub uppercase_keywords: bool,
+    /// Blank lines inserted between separate queries.
+    pub lines_between_queries: u8,
+}
+
+impl Default for SqlFormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: "  ".to_string(),
+            uppercase_keywords: false,
+            lines_between_queries: 1,
+        }
+    }
+}
+
+impl SqlFormatOptions {
+    fn to_format_options(&self) -> sqlformat::FormatOptions {
+        let indent = if self.indent.contains('\t') {
+            sqlformat::Indent::Tabs
+        } else {
+            sqlformat::Indent::Spaces(self.indent.len() as u8)
+        };
+        let mut options = sqlformat::FormatOptions::default();
+        options.indent = indent;
+        options.uppercase = self.uppercase_keywords;
+        options.lines_between_queries = self.lines_between_queries;
+        options
+    }
+}
+
+// Process-global accounting so that, even when statement events are sampled
+// away, users can reconstruct true throughput from the periodic summary.
+static TOTAL_STATEMENTS: AtomicU64 = AtomicU64::new(0);
+static LOGGED_STATEMENTS: AtomicU64 = AtomicU64::new(0);
+
+// How often (in total statements executed) to emit the sampling summary event.
+const SUMMARY_INTERVAL: u64 = 10_000;
+
+thread_local! {
+    // Cheap per-thread RNG used for the one sampling decision made per logger.
+    static SAMPLING_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_entropy());
+}
 
 pub struct QueryLogger<'q> {
     sql: &'q str,
@@ -64,29 +157,54 @@ pub struct QueryLogger<'q> {
     rows_affected: u64,
     start: Instant,
     settings: LogSettings,
-    span: Span,
+    // Whether this statement won the sampling draw made once at construction.
+    // Slow statements bypass this and are always logged.
+    sampled: bool,
+    // Kept entered for the logger's whole lifetime (an RAII guard) so that from
+    // construction until `Drop` the query span is the current span and
+    // connection acquisition, prepare, execute and fetch attach as its
+    // children, giving the span a real duration.
+    span: EnteredSpan,
 }
 
 impl<'q> QueryLogger<'q> {
     pub fn new(sql: &'q str, settings: LogSettings) -> Self {
-        let trimmed_query = trim_query(sql);
+        let summary = parse_query_summary(sql);
+        // The operation keyword (`SELECT`, `INSERT`, â€¦) is the first token of
+        // the summary; APM tooling keys `db.operation` off it.
+        let operation = summary
+            .split(' ')
+            .next()
+            .unwrap_or(summary.as_str())
+            .to_owned();
+        let sampled = sample_statement(settings.sample_rate);
+        // Open the span at the configured statements level so the query tree
+        // respects the same verbosity knob as the statement events. When
+        // statement logging is `Off` the span is still opened, at `INFO`.
+        let span_level = private_level_filter_to_levels(settings.statements_level)
+            .map(|(tracing_level, _)| tracing_level)
+            .unwrap_or(tracing::Level::INFO);
+        let span = private_tracing_dynamic_span!(
+            target: "sqlx::query-trace",
+            span_level,
+            "query",
+            resource.name = summary.as_str(),
+            "span.type" = "db",
+            span.kind = "client",
+            service = "sqlx",
+            db.system = settings.db_system,
+            db.operation = operation.as_str(),
+            db.row_count = tracing::field::Empty,
+        )
+        .entered();
         Self {
             sql,
             rows_returned: 0,
             rows_affected: 0,
             start: Instant::now(),
+            sampled,
             settings,
-            span: info_span!(
-                target: "sqlx::query-trace",
-                "query",
-                resource.name = trimmed_query.as_str(),
-                "span.type" = "db",
-                span.kind = "client",
-                service = "sqlx",
-                db.system = "postgres",
-                db.operation = trimmed_query.as_str(),
-                db.row_count = tracing::field::Empty,
-            ),
+            span,
         }
     }
 
@@ -111,6 +229,39 @@ impl<'q> QueryLogger<'q> {
 
         let was_slow = elapsed >= self.settings.slow_statements_duration;
 
+        // Account for every statement so the summary reflects true throughput,
+        // then decide whether this one should be logged. Slow statements always
+        // bypass sampling so alerting stays reliable.
+        let should_log = was_slow || self.sampled;
+        let total = TOTAL_STATEMENTS.fetch_add(1, Ordering::Relaxed) + 1;
+        if should_log {
+            LOGGED_STATEMENTS.fetch_add(1, Ordering::Relaxed);
+        }
+        if total % SUMMARY_INTERVAL == 0 {
+            // Route the summary through the same level/enabled handling as the
+            // statement events so it honors `statements_level` and stays silent
+            // when that target's logging is disabled.
+            if let Some((tracing_level, log_level)) =
+                private_level_filter_to_levels(self.settings.statements_level)
+            {
+                let summary_is_enabled = log::log_enabled!(target: "sqlx::query", log_level)
+                    || private_tracing_dynamic_enabled!(target: "sqlx::query", tracing_level);
+                if summary_is_enabled {
+                    let logged = LOGGED_STATEMENTS.load(Ordering::Relaxed);
+                    private_tracing_dynamic_event!(
+                        target: "sqlx::query",
+                        tracing_level,
+                        total,
+                        logged,
+                        "{total} statements executed, {logged} logged"
+                    );
+                }
+            }
+        }
+        if !should_log {
+            return;
+        }
+
         let lvl = if was_slow {
             self.settings.slow_statements_level
         } else {
@@ -127,14 +278,16 @@ impl<'q> QueryLogger<'q> {
 
                 let sql = if summary != self.sql {
                     summary.push_str(" â€¦");
-                    format!(
-                        "\n\n{}\n",
-                        sqlformat::format(
+                    let rendered = match &self.settings.statement_format {
+                        StatementFormat::Pretty(options) => sqlformat::format(
                             &self.sql,
                             &sqlformat::QueryParams::None,
-                            sqlformat::FormatOptions::default()
-                        )
-                    )
+                            options.to_format_options(),
+                        ),
+                        StatementFormat::Verbatim => trim_query(&self.sql),
+                        StatementFormat::SingleLine => collapse_to_single_line(&self.sql),
+                    };
+                    format!("\n\n{}\n", rendered)
                 } else {
                     String::new()
                 };
@@ -182,12 +335,268 @@ impl<'q> Drop for QueryLogger<'q> {
     }
 }
 
+// Draw a single `f64` from the thread-local RNG and decide whether this
+// statement should be logged. A `sample_rate` of `1.0` always logs and `0.0`
+// never does, short-circuiting the draw in both cases.
+fn sample_statement(sample_rate: f64) -> bool {
+    if sample_rate >= 1.0 {
+        return true;
+    }
+    if sample_rate <= 0.0 {
+        return false;
+    }
+    SAMPLING_RNG.with(|rng| rng.borrow_mut().gen::<f64>() < sample_rate)
+}
+
+// Collapse a statement onto a single line by squeezing every run of
+// whitespace (including newlines) into a single space.
+fn collapse_to_single_line(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
 pub fn parse_query_summary(sql: &str) -> String {
-    // For now, just take the first 4 words
-    sql.split_whitespace()
-        .take(4)
-        .collect::<Vec<&str>>()
-        .join(" ")
+    // Prefer a structured summary derived from the statement's tokens; fall
+    // back to the first four words when no recognizable operation is found.
+    summarize_statement(sql).unwrap_or_else(|| {
+        sql.split_whitespace()
+            .take(4)
+            .collect::<Vec<&str>>()
+            .join(" ")
+    })
+}
+
+// Top-level operations whose first occurrence names the statement.
+const OPERATION_KEYWORDS: &[&str] = &[
+    "SELECT", "INSERT", "UPDATE", "DELETE", "WITH", "CREATE", "DROP", "ALTER", "TRUNCATE", "BEGIN",
+    "COMMIT", "ROLLBACK", "SET", "CALL", "EXPLAIN", "MERGE", "REPLACE", "GRANT", "REVOKE",
+];
+
+// Operations that, inside a `WITH` prelude, name the statement once the CTE
+// definitions (which live inside parentheses) are stepped over.
+const CTE_MAIN_OPERATIONS: &[&str] = &["SELECT", "INSERT", "UPDATE", "DELETE", "MERGE"];
+
+// Keywords after which the next identifier is the primary table name.
+const TABLE_INTRODUCERS: &[&str] = &["FROM", "INTO", "UPDATE"];
+
+// Keywords that may sit between a table introducer and the table itself and
+// must be stepped over (e.g. `FROM ONLY foo`, `DELETE FROM LATERAL ...`).
+const TABLE_MODIFIERS: &[&str] = &["ONLY", "LATERAL"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    // A bare word: either a reserved keyword or an identifier.
+    Word,
+    // A delimited identifier: `"foo"`, `` `foo` `` or `[foo]`.
+    Quoted,
+    // A string literal or a comment — never part of a summary.
+    Ignorable,
+    // Any single punctuation character, e.g. `(`, `)`, `,`, `;`.
+    Punct,
+}
+
+struct Token<'a> {
+    kind: TokenKind,
+    value: &'a str,
+}
+
+// A small SQL tokenizer that classifies the statement into words, delimited
+// identifiers, punctuation and ignorable spans (comments, string literals);
+// whitespace is skipped without producing a token. `sqlformat`'s own tokenizer is not exported, so we scan here, but
+// with the same token granularity the summary logic needs: comments and string
+// bodies never leak into the summary and punctuation is split off cleanly.
+fn tokenize(sql: &str) -> Vec<Token<'_>> {
+    let cs: Vec<(usize, char)> = sql.char_indices().collect();
+    let len = sql.len();
+    let byte_at = |k: usize| cs.get(k).map(|&(i, _)| i).unwrap_or(len);
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < cs.len() {
+        let (start, c) = cs[i];
+        match c {
+            _ if c.is_whitespace() => {
+                let mut j = i + 1;
+                while j < cs.len() && cs[j].1.is_whitespace() {
+                    j += 1;
+                }
+                i = j;
+            }
+            // `-- line comment`
+            '-' if cs.get(i + 1).map(|&(_, c)| c) == Some('-') => {
+                let mut j = i + 2;
+                while j < cs.len() && cs[j].1 != '\n' {
+                    j += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Ignorable,
+                    value: &sql[start..byte_at(j)],
+                });
+                i = j;
+            }
+            // `/* block comment */`
+            '/' if cs.get(i + 1).map(|&(_, c)| c) == Some('*') => {
+                let mut j = i + 2;
+                while j + 1 < cs.len() && !(cs[j].1 == '*' && cs[j + 1].1 == '/') {
+                    j += 1;
+                }
+                j = (j + 2).min(cs.len());
+                tokens.push(Token {
+                    kind: TokenKind::Ignorable,
+                    value: &sql[start..byte_at(j)],
+                });
+                i = j;
+            }
+            // String literal; `''` is an escaped quote, not a terminator.
+            '\'' => {
+                let mut j = i + 1;
+                while j < cs.len() {
+                    if cs[j].1 == '\'' {
+                        if cs.get(j + 1).map(|&(_, c)| c) == Some('\'') {
+                            j += 2;
+                            continue;
+                        }
+                        j += 1;
+                        break;
+                    }
+                    j += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Ignorable,
+                    value: &sql[start..byte_at(j)],
+                });
+                i = j;
+            }
+            // Delimited identifier.
+            '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                let mut j = i + 1;
+                while j < cs.len() && cs[j].1 != close {
+                    j += 1;
+                }
+                j = (j + 1).min(cs.len());
+                tokens.push(Token {
+                    kind: TokenKind::Quoted,
+                    value: &sql[start..byte_at(j)],
+                });
+                i = j;
+            }
+            // Bare word: identifier or keyword. Dots keep qualified names
+            // (`schema.table`) together.
+            _ if c.is_alphanumeric() || c == '_' || c == '$' => {
+                let mut j = i + 1;
+                while j < cs.len()
+                    && (cs[j].1.is_alphanumeric()
+                        || cs[j].1 == '_'
+                        || cs[j].1 == '$'
+                        || cs[j].1 == '.')
+                {
+                    j += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Word,
+                    value: &sql[start..byte_at(j)],
+                });
+                i = j;
+            }
+            _ => {
+                tokens.push(Token {
+                    kind: TokenKind::Punct,
+                    value: &sql[start..byte_at(i + 1)],
+                });
+                i += 1;
+            }
+        }
+    }
+
+    tokens
+}
+
+// Tokenize the statement, take the first top-level reserved keyword as the
+// operation (stepping past a `WITH` prelude to the statement it introduces),
+// then capture the first identifier following `FROM`/`INTO`/`UPDATE` at the
+// same nesting level as the primary table, yielding summaries like
+// `SELECT users` or `INSERT events`. Derived tables (`FROM (SELECT ...)`) carry
+// no name, so the summary is just the operation.
+fn summarize_statement(sql: &str) -> Option<String> {
+    let tokens = tokenize(sql);
+
+    // Find the operation keyword, tracking parenthesis depth so CTE bodies
+    // (which are parenthesized) don't masquerade as the main statement.
+    let mut depth = 0i32;
+    let mut op_idx = None;
+    for (idx, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Punct if token.value == "(" => depth += 1,
+            TokenKind::Punct if token.value == ")" => depth = depth.saturating_sub(1),
+            TokenKind::Word if depth == 0 => {
+                let upper = token.value.to_ascii_uppercase();
+                if op_idx.is_none() && OPERATION_KEYWORDS.contains(&upper.as_str()) {
+                    op_idx = Some(idx);
+                    if upper != "WITH" {
+                        break;
+                    }
+                    // `WITH`: keep scanning at depth 0 for the statement the CTE
+                    // feeds, e.g. the outer `SELECT`/`INSERT`/...
+                } else if op_idx.is_some() && CTE_MAIN_OPERATIONS.contains(&upper.as_str()) {
+                    op_idx = Some(idx);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let op_idx = op_idx?;
+    let operation = tokens[op_idx].value.to_ascii_uppercase();
+
+    // Scan from the operation keyword (inclusive, so `UPDATE accounts` works)
+    // for a top-level table introducer, then the table identifier after it.
+    let mut depth = 0i32;
+    let mut awaiting_table = false;
+    let mut table = None;
+    for token in &tokens[op_idx..] {
+        match token.kind {
+            TokenKind::Punct if token.value == "(" => {
+                if awaiting_table {
+                    // Derived table / subquery — no bare name to report.
+                    break;
+                }
+                depth += 1;
+            }
+            TokenKind::Punct if token.value == ")" => depth = depth.saturating_sub(1),
+            TokenKind::Word | TokenKind::Quoted if depth == 0 => {
+                if awaiting_table {
+                    if token.kind == TokenKind::Word
+                        && TABLE_MODIFIERS.contains(&token.value.to_ascii_uppercase().as_str())
+                    {
+                        continue;
+                    }
+                    let name = clean_identifier(token.value);
+                    if !name.is_empty() {
+                        table = Some(name);
+                    }
+                    break;
+                }
+                if TABLE_INTRODUCERS.contains(&token.value.to_ascii_uppercase().as_str()) {
+                    awaiting_table = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(match table {
+        Some(table) => format!("{} {}", operation, table),
+        None => operation,
+    })
+}
+
+// Strip the delimiters from a quoted identifier (`"events"` -> `events`); bare
+// identifiers carry no delimiters and pass through unchanged.
+fn clean_identifier(token: &str) -> String {
+    token
+        .trim_matches(|c: char| matches!(c, '"' | '`' | '\'' | '[' | ']'))
+        .to_string()
 }
 
 pub fn trim_query(sql: &str) -> String {
@@ -285,4 +694,64 @@ mod tests {
         let sql = "\n\n";
         assert_eq!(trim_query(sql), "");
     }
+
+    #[test]
+    fn test_parse_query_summary_select() {
+        assert_eq!(
+            parse_query_summary("SELECT id, name FROM users WHERE age > 18"),
+            "SELECT users"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_summary_insert() {
+        assert_eq!(
+            parse_query_summary("INSERT INTO events (kind) VALUES ($1)"),
+            "INSERT events"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_summary_update() {
+        assert_eq!(
+            parse_query_summary("UPDATE accounts SET balance = 0"),
+            "UPDATE accounts"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_summary_skips_leading_comment() {
+        let sql = "-- fetch active users\n   SELECT * FROM users";
+        assert_eq!(parse_query_summary(sql), "SELECT users");
+    }
+
+    #[test]
+    fn test_parse_query_summary_falls_back_to_words() {
+        let sql = "VACUUM ANALYZE";
+        assert_eq!(parse_query_summary(sql), "VACUUM ANALYZE");
+    }
+
+    #[test]
+    fn test_parse_query_summary_derived_table_has_no_name() {
+        let sql = "SELECT * FROM (SELECT id FROM users) AS u";
+        assert_eq!(parse_query_summary(sql), "SELECT");
+    }
+
+    #[test]
+    fn test_parse_query_summary_cte_uses_outer_statement() {
+        let sql = "WITH active AS (SELECT id FROM logins) SELECT * FROM users";
+        assert_eq!(parse_query_summary(sql), "SELECT users");
+    }
+
+    #[test]
+    fn test_parse_query_summary_quoted_identifier() {
+        let sql = "SELECT * FROM \"events\"";
+        assert_eq!(parse_query_summary(sql), "SELECT events");
+    }
+
+    #[test]
+    fn test_parse_query_summary_skips_from_modifier() {
+        let sql = "SELECT * FROM ONLY shards";
+        assert_eq!(parse_query_summary(sql), "SELECT shards");
+    }
 }